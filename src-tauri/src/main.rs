@@ -2,22 +2,32 @@
 
 use chrono::{Local, Utc};
 use keyring::{Entry, Error as KeyringError};
+use memmap2::Mmap;
 use rfd::FileDialog;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager, State};
 use url::Url;
-use walkdir::WalkDir;
 
 const SOURCES_FILE: &str = "library-sources.json";
+const SCAN_CACHE_FILE: &str = "library-scan-cache.json";
 const LIBRARY_MANIFEST_SCHEMA: &str = "reader-library-manifest";
 const LIBRARY_MANIFEST_VERSION: u32 = 1;
+/// `major.minor`; only the major component is enforced on import.
+const LIBRARY_MANIFEST_SCHEMA_VERSION: &str = "1.0";
+const LIBRARY_MANIFEST_SCHEMA_MAJOR: &str = "1";
+const LIBRARY_ARCHIVE_MAGIC: &[u8; 8] = b"RPACK001";
 const CORPUS_FAMILIES: [&str; 2] = ["wiki", "prose"];
 const CORPUS_TIERS: [&str; 3] = ["easy", "medium", "hard"];
+const CORPUS_INDEX_MAGIC: &[u8; 8] = b"RCIDX001";
+const CORPUS_INDEX_SCHEMA: &str = "reader-corpus-index";
+const CORPUS_INDEX_VERSION: u32 = 1;
+const NETWORK_FILESYSTEM_TYPES: [&str; 7] = ["nfs", "nfs4", "cifs", "smb", "smbfs", "fuse.sshfs", "9p"];
 const SUPPORTED_BOOK_EXTENSIONS: [&str; 3] = ["pdf", "epub", "txt"];
 const SUPPORTED_API_KEY_IDS: [&str; 1] = ["comprehension-gemini"];
 const SECURE_KEYRING_SERVICE: &str = "com.cmf.reader";
@@ -26,6 +36,19 @@ const SECURE_KEYRING_SERVICE: &str = "com.cmf.reader";
 struct LibrarySource {
     name: String,
     path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    scopes: Vec<SourceScopeRule>,
+}
+
+/// An allow/deny rule gating access to a subset of a source; matching `deny` rules win.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SourceScopeRule {
+    #[serde(rename = "pathGlob")]
+    path_glob: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extensions: Option<Vec<String>>,
+    #[serde(default)]
+    deny: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -43,6 +66,25 @@ struct LibraryItem {
     is_frontmatter: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScanCacheItem {
+    name: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    size: u64,
+    #[serde(rename = "modifiedAt")]
+    modified_at: f64,
+    #[serde(rename = "isFrontmatter")]
+    is_frontmatter: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct ScanCacheDirectory {
+    #[serde(rename = "mtimeMillis")]
+    mtime_millis: u64,
+    items: Vec<ScanCacheItem>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct ExtractedChapter {
     title: String,
@@ -61,6 +103,8 @@ struct ExtractedContent {
     page_count: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     chapters: Option<Vec<ExtractedChapter>>,
+    #[serde(rename = "detectedEncoding", skip_serializing_if = "Option::is_none")]
+    detected_encoding: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +112,8 @@ struct LibraryManifestSource {
     name: String,
     #[serde(rename = "rootName")]
     root_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    scopes: Vec<SourceScopeRule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,18 +132,35 @@ struct LibraryManifestEntry {
     size: u64,
     #[serde(rename = "modifiedAt")]
     modified_at: f64,
+    #[serde(rename = "contentHash", skip_serializing_if = "Option::is_none")]
+    content_hash: Option<String>,
+    #[serde(
+        rename = "normalizedTextContentHash",
+        skip_serializing_if = "Option::is_none"
+    )]
+    normalized_text_content_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct LibraryManifest {
     schema: String,
     version: u32,
+    #[serde(rename = "schemaVersion")]
+    schema_version: String,
     #[serde(rename = "exportedAt")]
     exported_at: String,
     sources: Vec<LibraryManifestSource>,
     entries: Vec<LibraryManifestEntry>,
 }
 
+/// One validation problem, located by JSON path (e.g. `$.entries[2].relativePath`).
+#[derive(Debug, Clone, Serialize)]
+struct ManifestValidationError {
+    #[serde(rename = "jsonPath")]
+    json_path: String,
+    message: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct LibraryExportResult {
     status: String,
@@ -134,6 +197,10 @@ struct LibraryImportResult {
     missing: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     results: Option<Vec<LibraryImportSourceResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<LibraryVerifySummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<Vec<ManifestValidationError>>,
 }
 
 #[derive(Debug, Clone)]
@@ -142,6 +209,31 @@ struct LibraryManifestImportSummary {
     existing: usize,
     missing: usize,
     results: Vec<LibraryImportSourceResult>,
+    verification: LibraryVerifySummary,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LibraryVerifySummary {
+    matched: usize,
+    modified: usize,
+    missing: usize,
+    unverified: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LibraryVerifyEntryResult {
+    #[serde(rename = "sourceName")]
+    source_name: String,
+    #[serde(rename = "relativePath")]
+    relative_path: String,
+    status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LibraryManifestVerifyResult {
+    status: String,
+    summary: LibraryVerifySummary,
+    entries: Vec<LibraryVerifyEntryResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -161,9 +253,37 @@ struct CorpusTierInfo {
     total_articles: usize,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct CorpusIndexRecord {
+    offset: u64,
+    length: u32,
+    fk_grade: f64,
+    words: u64,
+}
+
+enum CorpusSourceBytes {
+    Mapped(Mmap),
+    Buffered(Vec<u8>),
+}
+
+impl CorpusSourceBytes {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            CorpusSourceBytes::Mapped(mmap) => &mmap[..],
+            CorpusSourceBytes::Buffered(bytes) => &bytes[..],
+        }
+    }
+}
+
+struct CorpusIndex {
+    records: Vec<CorpusIndexRecord>,
+    sorted_by_grade: Vec<u32>,
+    source: CorpusSourceBytes,
+}
+
 #[derive(Default)]
 struct AppState {
-    corpus_cache: Mutex<HashMap<String, Vec<CorpusArticle>>>,
+    corpus_cache: Mutex<HashMap<String, CorpusIndex>>,
 }
 
 fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
@@ -238,26 +358,111 @@ fn is_within_root(target_path: &Path, root_path: &Path) -> bool {
     target_path == root_path || target_path.starts_with(root_path)
 }
 
-fn get_allowed_library_roots(app: &AppHandle) -> Vec<PathBuf> {
+/// Matches a glob segment-by-segment: `?` is one non-`/` char, `*` is any
+/// run of non-`/` chars, `**` is any run of chars including `/`.
+fn glob_matches(pattern: &str, candidate: &str) -> bool {
+    fn match_from(pattern: &[u8], text: &[u8], pi: usize, ti: usize) -> bool {
+        if pi == pattern.len() {
+            return ti == text.len();
+        }
+
+        match pattern[pi] {
+            b'*' => {
+                let is_double_star = pi + 1 < pattern.len() && pattern[pi + 1] == b'*';
+                let next_pi = if is_double_star { pi + 2 } else { pi + 1 };
+                for next_ti in ti..=text.len() {
+                    if !is_double_star && text[ti..next_ti].contains(&b'/') {
+                        break;
+                    }
+                    if match_from(pattern, text, next_pi, next_ti) {
+                        return true;
+                    }
+                }
+                false
+            }
+            b'?' => {
+                ti < text.len() && text[ti] != b'/' && match_from(pattern, text, pi + 1, ti + 1)
+            }
+            literal => ti < text.len() && text[ti] == literal && match_from(pattern, text, pi + 1, ti + 1),
+        }
+    }
+
+    match_from(pattern.as_bytes(), candidate.as_bytes(), 0, 0)
+}
+
+fn scope_rule_matches(rule: &SourceScopeRule, relative_path: &str, extension: Option<&str>) -> bool {
+    if !glob_matches(&rule.path_glob, relative_path) {
+        return false;
+    }
+
+    match (&rule.extensions, extension) {
+        (Some(extensions), Some(candidate)) => extensions
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(candidate)),
+        _ => true,
+    }
+}
+
+/// An empty scope list means blanket access; otherwise a path needs a matching allow rule and no matching deny rule.
+fn is_path_allowed_by_scopes(
+    scopes: &[SourceScopeRule],
+    relative_path: &str,
+    extension: Option<&str>,
+) -> bool {
+    if scopes.is_empty() {
+        return true;
+    }
+
+    let mut matched_allow = false;
+    for rule in scopes {
+        if !scope_rule_matches(rule, relative_path, extension) {
+            continue;
+        }
+        if rule.deny {
+            return false;
+        }
+        matched_allow = true;
+    }
+    matched_allow
+}
+
+fn find_owning_library_source(app: &AppHandle, normalized: &Path) -> Option<(LibrarySource, PathBuf)> {
     load_sources(app)
         .into_iter()
-        .filter_map(|source| normalize_path(&source.path))
-        .collect()
+        .filter_map(|source| normalize_path(&source.path).map(|root| (source, root)))
+        .find(|(_, root)| is_within_root(normalized, root))
 }
 
-fn resolve_allowed_library_path(app: &AppHandle, requested_path: &str) -> Result<PathBuf, String> {
+/// Directories are only checked for ownership; `scan_directory` enforces scopes per-listed-file.
+fn resolve_allowed_library_path(
+    app: &AppHandle,
+    requested_path: &str,
+) -> Result<(PathBuf, PathBuf, Vec<SourceScopeRule>), String> {
     let normalized =
         normalize_path(requested_path).ok_or_else(|| "Path does not exist".to_string())?;
-    let roots = get_allowed_library_roots(app);
-    if roots.is_empty() {
-        return Err("No library sources configured".to_string());
-    }
 
-    if !roots.iter().any(|root| is_within_root(&normalized, root)) {
+    let Some((source, root)) = find_owning_library_source(app, &normalized) else {
         return Err("Path is outside configured library sources".to_string());
+    };
+
+    if !source.scopes.is_empty() && !is_directory(&normalized) {
+        let relative_path = normalize_path_fragment(
+            normalized.strip_prefix(&root).unwrap_or(Path::new("")),
+        );
+        let extension = normalized
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+
+        if !is_path_allowed_by_scopes(&source.scopes, &relative_path, extension.as_deref()) {
+            return Err(format!(
+                "Path \"{relative_path}\" is blocked by source \"{}\" scope rules",
+                source.name
+            ));
+        }
     }
 
-    Ok(normalized)
+    Ok((normalized, root, source.scopes))
 }
 
 fn is_frontmatter_filename(filename: &str) -> bool {
@@ -288,72 +493,270 @@ fn normalize_manifest_path(path: &Path) -> String {
     path.to_string_lossy().replace('\\', "/")
 }
 
-fn scan_directory(root_path: &Path) -> Vec<LibraryItem> {
-    let mut items: Vec<LibraryItem> = vec![];
-
-    for entry in WalkDir::new(root_path)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(Result::ok)
-    {
-        if !entry.file_type().is_file() {
-            continue;
+/// Rejects an absolute path or one containing `..`, which would let `Path::join` escape the base dir (zip-slip).
+fn reject_unsafe_relative_path(relative: &str) -> Result<(), String> {
+    let candidate = Path::new(relative);
+    for component in candidate.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                return Err(format!(
+                    "Archive path \"{relative}\" escapes the destination directory"
+                ));
+            }
+            std::path::Component::Prefix(_) | std::path::Component::RootDir => {
+                return Err(format!("Archive path \"{relative}\" is absolute"));
+            }
+            _ => {}
         }
+    }
+    Ok(())
+}
 
-        let file_path = entry.path();
-        let extension = file_path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| ext.to_ascii_lowercase());
-        let Some(extension) = extension else {
-            continue;
-        };
+fn sort_library_items(items: &mut [LibraryItem]) {
+    items.sort_by(|a, b| {
+        let parent_a = a.parent_dir.as_deref().unwrap_or("");
+        let parent_b = b.parent_dir.as_deref().unwrap_or("");
+        parent_a.cmp(parent_b).then_with(|| a.name.cmp(&b.name))
+    });
+}
 
-        if !SUPPORTED_BOOK_EXTENSIONS.contains(&extension.as_str()) {
-            continue;
-        }
+fn scan_cache_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(app_data_dir(app)?.join(SCAN_CACHE_FILE))
+}
 
-        let Ok(metadata) = entry.metadata() else {
-            continue;
-        };
+fn load_scan_cache(app: &AppHandle) -> HashMap<String, HashMap<String, ScanCacheDirectory>> {
+    let Ok(path) = scan_cache_path(app) else {
+        return HashMap::new();
+    };
+    if !path.exists() {
+        return HashMap::new();
+    }
+    let Ok(raw) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    serde_json::from_str(&raw).unwrap_or_default()
+}
 
-        let modified_at = metadata
-            .modified()
-            .ok()
-            .and_then(|timestamp| timestamp.duration_since(UNIX_EPOCH).ok())
-            .map(|duration| duration.as_millis() as f64)
-            .unwrap_or(0.0);
-
-        let relative = file_path.strip_prefix(root_path).ok();
-        let parent_dir = relative
-            .and_then(|fragment| fragment.parent())
-            .and_then(|parent| {
-                if parent.as_os_str().is_empty() {
-                    None
-                } else {
-                    Some(normalize_path_fragment(parent))
+fn save_scan_cache(
+    app: &AppHandle,
+    cache: &HashMap<String, HashMap<String, ScanCacheDirectory>>,
+) -> Result<(), String> {
+    let path = scan_cache_path(app)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create scan cache directory: {err}"))?;
+    }
+    let payload = serde_json::to_string_pretty(cache)
+        .map_err(|err| format!("Failed to serialize scan cache: {err}"))?;
+    fs::write(path, payload).map_err(|err| format!("Failed to save scan cache: {err}"))
+}
+
+fn remove_scan_cache_source(app: &AppHandle, root_path: &Path) {
+    let mut full_cache = load_scan_cache(app);
+    if full_cache.remove(&normalize_path_fragment(root_path)).is_some() {
+        let _ = save_scan_cache(app, &full_cache);
+    }
+}
+
+fn scan_cache_item_to_library_item(
+    root_path: &Path,
+    dir_relative: &str,
+    item: &ScanCacheItem,
+) -> LibraryItem {
+    let file_path = if dir_relative.is_empty() {
+        root_path.join(&item.name)
+    } else {
+        root_path.join(dir_relative).join(&item.name)
+    };
+
+    LibraryItem {
+        name: item.name.clone(),
+        path: file_path.to_string_lossy().to_string(),
+        item_type: item.item_type.clone(),
+        size: item.size,
+        modified_at: item.modified_at,
+        parent_dir: if dir_relative.is_empty() {
+            None
+        } else {
+            Some(dir_relative.to_string())
+        },
+        is_frontmatter: Some(item.is_frontmatter),
+    }
+}
+
+/// Reuses cached entries when the directory's own mtime hasn't advanced, then recurses into subdirectories.
+fn scan_directory_recursive(
+    root_path: &Path,
+    dir_path: &Path,
+    cache: &mut HashMap<String, ScanCacheDirectory>,
+    seen_dirs: &mut HashSet<String>,
+    items: &mut Vec<LibraryItem>,
+) {
+    let dir_key = relative_dir_key(root_path, dir_path);
+    seen_dirs.insert(dir_key.clone());
+
+    let Ok(dir_metadata) = fs::metadata(dir_path) else {
+        return;
+    };
+    let dir_mtime = file_mtime_millis(&dir_metadata);
+    let is_fresh = cache
+        .get(&dir_key)
+        .map(|entry| entry.mtime_millis == dir_mtime)
+        .unwrap_or(false);
+
+    let mut subdirectories: Vec<PathBuf> = vec![];
+    let Ok(read_dir) = fs::read_dir(dir_path) else {
+        return;
+    };
+
+    if is_fresh {
+        // In-place edits advance a file's mtime but not its parent directory's, so re-stat each cached item.
+        let mut refreshed_items: Vec<ScanCacheItem> = vec![];
+        let mut any_refreshed = false;
+        for item in &cache[&dir_key].items {
+            let item_path = dir_path.join(&item.name);
+            let Ok(metadata) = fs::metadata(&item_path) else {
+                any_refreshed = true;
+                continue;
+            };
+            let current_mtime = file_mtime_millis(&metadata) as f64;
+            let current_item = if current_mtime == item.modified_at && metadata.len() == item.size {
+                item.clone()
+            } else {
+                any_refreshed = true;
+                ScanCacheItem {
+                    name: item.name.clone(),
+                    item_type: item.item_type.clone(),
+                    size: metadata.len(),
+                    modified_at: current_mtime,
+                    is_frontmatter: item.is_frontmatter,
                 }
+            };
+            items.push(scan_cache_item_to_library_item(root_path, &dir_key, &current_item));
+            refreshed_items.push(current_item);
+        }
+        if any_refreshed {
+            cache.insert(
+                dir_key.clone(),
+                ScanCacheDirectory {
+                    mtime_millis: dir_mtime,
+                    items: refreshed_items,
+                },
+            );
+        }
+        for entry in read_dir.filter_map(Result::ok) {
+            if entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false) {
+                subdirectories.push(entry.path());
+            }
+        }
+    } else {
+        let mut fresh_items: Vec<ScanCacheItem> = vec![];
+        for entry in read_dir.filter_map(Result::ok) {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                subdirectories.push(entry.path());
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+
+            let file_path = entry.path();
+            let extension = file_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.to_ascii_lowercase());
+            let Some(extension) = extension else {
+                continue;
+            };
+            if !SUPPORTED_BOOK_EXTENSIONS.contains(&extension.as_str()) {
+                continue;
+            }
+
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let modified_at = file_mtime_millis(&metadata) as f64;
+            let filename = entry.file_name().to_string_lossy().to_string();
+
+            fresh_items.push(ScanCacheItem {
+                name: filename.clone(),
+                item_type: extension,
+                size: metadata.len(),
+                modified_at,
+                is_frontmatter: is_frontmatter_filename(&filename),
             });
+        }
 
-        let filename = entry.file_name().to_string_lossy().to_string();
-        items.push(LibraryItem {
-            name: filename.clone(),
-            path: file_path.to_string_lossy().to_string(),
-            item_type: extension,
-            size: metadata.len(),
-            modified_at,
-            parent_dir,
-            is_frontmatter: Some(is_frontmatter_filename(&filename)),
-        });
+        for item in &fresh_items {
+            items.push(scan_cache_item_to_library_item(root_path, &dir_key, item));
+        }
+
+        cache.insert(
+            dir_key.clone(),
+            ScanCacheDirectory {
+                mtime_millis: dir_mtime,
+                items: fresh_items,
+            },
+        );
     }
 
-    items.sort_by(|a, b| {
-        let parent_a = a.parent_dir.as_deref().unwrap_or("");
-        let parent_b = b.parent_dir.as_deref().unwrap_or("");
-        parent_a.cmp(parent_b).then_with(|| a.name.cmp(&b.name))
-    });
+    for subdirectory in subdirectories {
+        scan_directory_recursive(root_path, &subdirectory, cache, seen_dirs, items);
+    }
+}
+
+fn relative_dir_key(root_path: &Path, dir_path: &Path) -> String {
+    if dir_path == root_path {
+        return String::new();
+    }
+    dir_path
+        .strip_prefix(root_path)
+        .map(normalize_path_fragment)
+        .unwrap_or_default()
+}
+
+/// Filters the scanned items against `scopes` so callers never see items outside the source's access policy.
+fn scan_directory(
+    app: &AppHandle,
+    root_path: &Path,
+    force_rebuild: bool,
+    scopes: &[SourceScopeRule],
+    scope_root: &Path,
+) -> Vec<LibraryItem> {
+    let source_key = normalize_path_fragment(root_path);
+    let mut full_cache = load_scan_cache(app);
+    let mut source_cache = if force_rebuild {
+        HashMap::new()
+    } else {
+        full_cache.remove(&source_key).unwrap_or_default()
+    };
+
+    let mut items = vec![];
+    let mut seen_dirs = HashSet::new();
+    scan_directory_recursive(root_path, root_path, &mut source_cache, &mut seen_dirs, &mut items);
+    source_cache.retain(|key, _| seen_dirs.contains(key));
+
+    full_cache.insert(source_key, source_cache);
+    let _ = save_scan_cache(app, &full_cache);
+
+    sort_library_items(&mut items);
 
+    if scopes.is_empty() {
+        return items;
+    }
     items
+        .into_iter()
+        .filter(|item| {
+            let relative_path = PathBuf::from(&item.path)
+                .strip_prefix(scope_root)
+                .map(normalize_path_fragment)
+                .unwrap_or_default();
+            is_path_allowed_by_scopes(scopes, &relative_path, Some(item.item_type.as_str()))
+        })
+        .collect()
 }
 
 fn source_root_name(source_path: &str) -> String {
@@ -383,7 +786,25 @@ fn resolve_normalized_text_snapshot(
     Some(normalize_manifest_path(relative_sidecar))
 }
 
-fn build_library_manifest(sources: &[LibrarySource]) -> LibraryManifest {
+/// Streams the file through BLAKE3 in fixed-size chunks so large PDFs aren't fully buffered.
+fn hash_file_streaming(path: &Path) -> Result<String, String> {
+    let mut file =
+        fs::File::open(path).map_err(|err| format!("Failed to open file for hashing: {err}"))?;
+    let mut hasher = blake3::Hasher::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|err| format!("Failed to read file for hashing: {err}"))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+fn build_library_manifest(app: &AppHandle, sources: &[LibrarySource]) -> LibraryManifest {
     let mut entries: Vec<LibraryManifestEntry> = vec![];
 
     for source in sources {
@@ -391,7 +812,7 @@ fn build_library_manifest(sources: &[LibrarySource]) -> LibraryManifest {
             continue;
         };
 
-        for item in scan_directory(&root_path) {
+        for item in scan_directory(app, &root_path, false, &source.scopes, &root_path) {
             let item_path = PathBuf::from(&item.path);
             let Ok(relative_path_raw) = item_path.strip_prefix(&root_path) else {
                 continue;
@@ -400,17 +821,21 @@ fn build_library_manifest(sources: &[LibrarySource]) -> LibraryManifest {
                 continue;
             }
 
+            let normalized_text_relative_path =
+                resolve_normalized_text_snapshot(&root_path, &item_path, &item.item_type);
+            let normalized_text_content_hash = normalized_text_relative_path
+                .as_ref()
+                .and_then(|relative| hash_file_streaming(&root_path.join(relative)).ok());
+
             entries.push(LibraryManifestEntry {
                 source_name: source.name.clone(),
                 relative_path: normalize_manifest_path(relative_path_raw),
                 entry_type: item.item_type.clone(),
-                normalized_text_relative_path: resolve_normalized_text_snapshot(
-                    &root_path,
-                    &item_path,
-                    &item.item_type,
-                ),
+                normalized_text_relative_path,
                 size: item.size,
                 modified_at: item.modified_at,
+                content_hash: hash_file_streaming(&item_path).ok(),
+                normalized_text_content_hash,
             });
         }
     }
@@ -418,12 +843,14 @@ fn build_library_manifest(sources: &[LibrarySource]) -> LibraryManifest {
     LibraryManifest {
         schema: LIBRARY_MANIFEST_SCHEMA.to_string(),
         version: LIBRARY_MANIFEST_VERSION,
+        schema_version: LIBRARY_MANIFEST_SCHEMA_VERSION.to_string(),
         exported_at: Utc::now().to_rfc3339(),
         sources: sources
             .iter()
             .map(|source| LibraryManifestSource {
                 name: source.name.clone(),
                 root_name: source_root_name(&source.path),
+                scopes: source.scopes.clone(),
             })
             .collect(),
         entries,
@@ -444,32 +871,426 @@ fn is_manifest_entry_type(value: &str) -> bool {
     value == "pdf" || value == "epub" || value == "txt"
 }
 
-fn load_library_manifest(manifest_path: &Path) -> Result<LibraryManifest, String> {
+/// Streams one `{relative_path, size, bytes}` frame of a `.readerpack` archive.
+fn write_archive_frame(
+    writer: &mut impl Write,
+    source_root: &Path,
+    source_relative_path: &str,
+    archive_relative_path: &str,
+) -> Result<(), String> {
+    let absolute = source_root.join(source_relative_path.trim_start_matches('/'));
+    let metadata = fs::metadata(&absolute)
+        .map_err(|err| format!("Failed to stat {archive_relative_path}: {err}"))?;
+    let size = metadata.len();
+
+    let path_bytes = archive_relative_path.as_bytes();
+    writer
+        .write_all(&(path_bytes.len() as u32).to_le_bytes())
+        .map_err(|err| format!("Failed to write archive frame: {err}"))?;
+    writer
+        .write_all(path_bytes)
+        .map_err(|err| format!("Failed to write archive frame: {err}"))?;
+    writer
+        .write_all(&size.to_le_bytes())
+        .map_err(|err| format!("Failed to write archive frame: {err}"))?;
+
+    let mut file = fs::File::open(&absolute)
+        .map_err(|err| format!("Failed to open {archive_relative_path}: {err}"))?;
+    let copied = std::io::copy(&mut file, writer)
+        .map_err(|err| format!("Failed to stream {archive_relative_path} into archive: {err}"))?;
+    if copied != size {
+        return Err(format!(
+            "{archive_relative_path} changed size while archiving"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads the next frame from a `.readerpack` stream, returning `None` at a clean end-of-archive boundary.
+fn read_archive_frame(reader: &mut impl Read) -> Result<Option<(String, u64)>, String> {
+    let mut path_len_buf = [0u8; 4];
+    match reader.read_exact(&mut path_len_buf) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(format!("Failed to read archive frame: {err}")),
+    }
+    let path_len = u32::from_le_bytes(path_len_buf) as usize;
+
+    let mut path_bytes = vec![0u8; path_len];
+    reader
+        .read_exact(&mut path_bytes)
+        .map_err(|err| format!("Failed to read archive frame path: {err}"))?;
+    let relative_path = String::from_utf8(path_bytes)
+        .map_err(|_| "Invalid archive frame path encoding".to_string())?;
+
+    let mut size_buf = [0u8; 8];
+    reader
+        .read_exact(&mut size_buf)
+        .map_err(|err| format!("Failed to read archive frame size: {err}"))?;
+    let size = u64::from_le_bytes(size_buf);
+
+    Ok(Some((relative_path, size)))
+}
+
+/// The JSON Schema (draft-07) describing [`LibraryManifest`] documents; [`validate_manifest_document`] enforces a subset of it.
+fn library_manifest_json_schema() -> serde_json::Value {
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Reader Library Manifest",
+        "type": "object",
+        "required": ["schema", "version", "schemaVersion", "exportedAt", "sources", "entries"],
+        "properties": {
+            "schema": { "const": LIBRARY_MANIFEST_SCHEMA },
+            "version": { "type": "integer" },
+            "schemaVersion": {
+                "type": "string",
+                "pattern": "^[0-9]+\\.[0-9]+$",
+                "description": "major.minor; this build rejects any major other than LIBRARY_MANIFEST_SCHEMA_MAJOR"
+            },
+            "exportedAt": { "type": "string" },
+            "sources": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["name", "rootName"],
+                    "properties": {
+                        "name": { "type": "string", "minLength": 1 },
+                        "rootName": { "type": "string", "minLength": 1 },
+                        "scopes": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["pathGlob"],
+                                "properties": {
+                                    "pathGlob": { "type": "string" },
+                                    "extensions": { "type": "array", "items": { "type": "string" } },
+                                    "deny": { "type": "boolean" }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "entries": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["sourceName", "relativePath", "type", "size", "modifiedAt"],
+                    "properties": {
+                        "sourceName": { "type": "string", "minLength": 1 },
+                        "relativePath": { "type": "string", "minLength": 1 },
+                        "type": { "enum": ["pdf", "epub", "txt"] },
+                        "normalizedTextRelativePath": { "type": "string" },
+                        "size": { "type": "integer" },
+                        "modifiedAt": { "type": "number" },
+                        "contentHash": { "type": "string" },
+                        "normalizedTextContentHash": { "type": "string" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Validates a raw manifest document before any typed parsing: the `schema` value, the required fields of the root,
+/// sources, scope rules and entries, and the major component of `schemaVersion`. The `schemaVersion` pattern and the
+/// types of optional fields (scope `extensions`/`deny`, hashes, sidecar paths) from [`library_manifest_json_schema`]
+/// are not checked here; a wrong type there fails the typed parse instead.
+fn validate_manifest_document(document: &serde_json::Value) -> Vec<ManifestValidationError> {
+    let mut errors: Vec<ManifestValidationError> = vec![];
+
+    let Some(root) = document.as_object() else {
+        errors.push(ManifestValidationError {
+            json_path: "$".to_string(),
+            message: "Manifest must be a JSON object".to_string(),
+        });
+        return errors;
+    };
+
+    match root.get("schema").and_then(|value| value.as_str()) {
+        Some(schema) if schema == LIBRARY_MANIFEST_SCHEMA => {}
+        Some(_) => errors.push(ManifestValidationError {
+            json_path: "$.schema".to_string(),
+            message: format!("Unsupported manifest schema, expected \"{LIBRARY_MANIFEST_SCHEMA}\""),
+        }),
+        None => errors.push(ManifestValidationError {
+            json_path: "$.schema".to_string(),
+            message: "Missing required string field \"schema\"".to_string(),
+        }),
+    }
+
+    if root.get("version").and_then(|value| value.as_u64()).is_none() {
+        errors.push(ManifestValidationError {
+            json_path: "$.version".to_string(),
+            message: "Missing required integer field \"version\"".to_string(),
+        });
+    }
+
+    match root.get("schemaVersion").and_then(|value| value.as_str()) {
+        Some(schema_version) => {
+            let major = schema_version.split('.').next().unwrap_or("");
+            if major != LIBRARY_MANIFEST_SCHEMA_MAJOR {
+                errors.push(ManifestValidationError {
+                    json_path: "$.schemaVersion".to_string(),
+                    message: format!(
+                        "Unsupported schemaVersion major \"{major}\"; this build only understands major version {LIBRARY_MANIFEST_SCHEMA_MAJOR}"
+                    ),
+                });
+            }
+        }
+        None => errors.push(ManifestValidationError {
+            json_path: "$.schemaVersion".to_string(),
+            message: "Missing required string field \"schemaVersion\"".to_string(),
+        }),
+    }
+
+    if root.get("exportedAt").and_then(|value| value.as_str()).is_none() {
+        errors.push(ManifestValidationError {
+            json_path: "$.exportedAt".to_string(),
+            message: "Missing required string field \"exportedAt\"".to_string(),
+        });
+    }
+
+    match root.get("sources").and_then(|value| value.as_array()) {
+        Some(sources) => {
+            for (index, source) in sources.iter().enumerate() {
+                let path = format!("$.sources[{index}]");
+                let Some(source_obj) = source.as_object() else {
+                    errors.push(ManifestValidationError {
+                        json_path: path,
+                        message: "Source entry must be an object".to_string(),
+                    });
+                    continue;
+                };
+
+                if source_obj
+                    .get("name")
+                    .and_then(|value| value.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .is_none()
+                {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.name"),
+                        message: "Missing non-empty string field \"name\"".to_string(),
+                    });
+                }
+                if source_obj
+                    .get("rootName")
+                    .and_then(|value| value.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .is_none()
+                {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.rootName"),
+                        message: "Missing non-empty string field \"rootName\"".to_string(),
+                    });
+                }
+
+                if let Some(scopes) = source_obj.get("scopes") {
+                    match scopes.as_array() {
+                        Some(scope_rules) => {
+                            for (scope_index, rule) in scope_rules.iter().enumerate() {
+                                let scope_path = format!("{path}.scopes[{scope_index}]");
+                                let Some(rule_obj) = rule.as_object() else {
+                                    errors.push(ManifestValidationError {
+                                        json_path: scope_path,
+                                        message: "Scope rule must be an object".to_string(),
+                                    });
+                                    continue;
+                                };
+                                if rule_obj.get("pathGlob").and_then(|value| value.as_str()).is_none() {
+                                    errors.push(ManifestValidationError {
+                                        json_path: format!("{scope_path}.pathGlob"),
+                                        message: "Missing required string field \"pathGlob\"".to_string(),
+                                    });
+                                }
+                            }
+                        }
+                        None => errors.push(ManifestValidationError {
+                            json_path: format!("{path}.scopes"),
+                            message: "\"scopes\" must be an array".to_string(),
+                        }),
+                    }
+                }
+            }
+        }
+        None => errors.push(ManifestValidationError {
+            json_path: "$.sources".to_string(),
+            message: "Missing required array field \"sources\"".to_string(),
+        }),
+    }
+
+    match root.get("entries").and_then(|value| value.as_array()) {
+        Some(entries) => {
+            for (index, entry) in entries.iter().enumerate() {
+                let path = format!("$.entries[{index}]");
+                let Some(entry_obj) = entry.as_object() else {
+                    errors.push(ManifestValidationError {
+                        json_path: path,
+                        message: "Entry must be an object".to_string(),
+                    });
+                    continue;
+                };
+
+                if entry_obj
+                    .get("sourceName")
+                    .and_then(|value| value.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .is_none()
+                {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.sourceName"),
+                        message: "Missing non-empty string field \"sourceName\"".to_string(),
+                    });
+                }
+                if entry_obj
+                    .get("relativePath")
+                    .and_then(|value| value.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .is_none()
+                {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.relativePath"),
+                        message: "Missing non-empty string field \"relativePath\"".to_string(),
+                    });
+                }
+                match entry_obj.get("type").and_then(|value| value.as_str()) {
+                    Some(entry_type) if is_manifest_entry_type(entry_type) => {}
+                    _ => errors.push(ManifestValidationError {
+                        json_path: format!("{path}.type"),
+                        message: "\"type\" must be one of \"pdf\", \"epub\", \"txt\"".to_string(),
+                    }),
+                }
+                if entry_obj.get("size").and_then(|value| value.as_u64()).is_none() {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.size"),
+                        message: "Missing required integer field \"size\"".to_string(),
+                    });
+                }
+                if entry_obj.get("modifiedAt").and_then(|value| value.as_f64()).is_none() {
+                    errors.push(ManifestValidationError {
+                        json_path: format!("{path}.modifiedAt"),
+                        message: "Missing required number field \"modifiedAt\"".to_string(),
+                    });
+                }
+            }
+        }
+        None => errors.push(ManifestValidationError {
+            json_path: "$.entries".to_string(),
+            message: "Missing required array field \"entries\"".to_string(),
+        }),
+    }
+
+    errors
+}
+
+/// Outcome of reading and schema-validating a manifest file.
+enum ManifestLoadOutcome {
+    Valid(LibraryManifest),
+    Invalid(Vec<ManifestValidationError>),
+}
+
+fn load_and_validate_library_manifest(manifest_path: &Path) -> Result<ManifestLoadOutcome, String> {
     let raw = fs::read_to_string(manifest_path)
         .map_err(|err| format!("Failed to read manifest: {err}"))?;
-    let parsed: LibraryManifest = serde_json::from_str(&raw)
-        .map_err(|err| format!("Invalid manifest JSON payload: {err}"))?;
+    let document: serde_json::Value =
+        serde_json::from_str(&raw).map_err(|err| format!("Invalid manifest JSON payload: {err}"))?;
 
-    if parsed.schema != LIBRARY_MANIFEST_SCHEMA || parsed.version != LIBRARY_MANIFEST_VERSION {
-        return Err("Unsupported library manifest format".to_string());
+    let errors = validate_manifest_document(&document);
+    if !errors.is_empty() {
+        return Ok(ManifestLoadOutcome::Invalid(errors));
     }
 
-    for source in &parsed.sources {
-        if source.name.trim().is_empty() || source.root_name.trim().is_empty() {
-            return Err("Invalid manifest source entry".to_string());
+    let manifest: LibraryManifest = serde_json::from_value(document)
+        .map_err(|err| format!("Invalid manifest JSON payload: {err}"))?;
+    Ok(ManifestLoadOutcome::Valid(manifest))
+}
+
+fn load_library_manifest(manifest_path: &Path) -> Result<LibraryManifest, String> {
+    match load_and_validate_library_manifest(manifest_path)? {
+        ManifestLoadOutcome::Valid(manifest) => Ok(manifest),
+        ManifestLoadOutcome::Invalid(errors) => {
+            let summary = errors
+                .iter()
+                .map(|error| format!("{} ({})", error.message, error.json_path))
+                .collect::<Vec<_>>()
+                .join("; ");
+            Err(format!("Invalid library manifest: {summary}"))
         }
     }
+}
 
-    for entry in &parsed.entries {
-        if entry.source_name.trim().is_empty()
-            || entry.relative_path.trim().is_empty()
-            || !is_manifest_entry_type(&entry.entry_type)
-        {
-            return Err("Invalid manifest content entry".to_string());
-        }
+/// Classifies each entry of a resolved source as `matched`, `modified`, `missing`, or `unverified`; entries whose
+/// source is not in `resolved_roots` are skipped. Never mutates sources.
+fn verify_manifest_entries(
+    manifest: &LibraryManifest,
+    resolved_roots: &HashMap<String, PathBuf>,
+) -> (LibraryVerifySummary, Vec<LibraryVerifyEntryResult>) {
+    let mut summary = LibraryVerifySummary {
+        matched: 0,
+        modified: 0,
+        missing: 0,
+        unverified: 0,
+    };
+    let mut entries = vec![];
+
+    for entry in &manifest.entries {
+        let Some(root) = resolved_roots.get(&entry.source_name) else {
+            continue;
+        };
+
+        let candidate = root.join(&entry.relative_path);
+        let status = if !candidate.is_file() {
+            summary.missing += 1;
+            "missing"
+        } else {
+            match &entry.content_hash {
+                None => {
+                    summary.unverified += 1;
+                    "unverified"
+                }
+                Some(expected) => match hash_file_streaming(&candidate) {
+                    Ok(actual) if &actual == expected => {
+                        summary.matched += 1;
+                        "matched"
+                    }
+                    Ok(_) => {
+                        summary.modified += 1;
+                        "modified"
+                    }
+                    Err(_) => {
+                        summary.unverified += 1;
+                        "unverified"
+                    }
+                },
+            }
+        };
+
+        entries.push(LibraryVerifyEntryResult {
+            source_name: entry.source_name.clone(),
+            relative_path: entry.relative_path.clone(),
+            status: status.to_string(),
+        });
     }
 
-    Ok(parsed)
+    (summary, entries)
+}
+
+/// Resolves `shared_root/root_name`, falling back to `shared_root` itself for a single-source manifest.
+fn resolve_manifest_source_root(
+    shared_root: &Path,
+    source: &LibraryManifestSource,
+    source_count: usize,
+) -> Option<PathBuf> {
+    let expected_root = shared_root.join(&source.root_name);
+    if is_directory(&expected_root) {
+        Some(fs::canonicalize(&expected_root).unwrap_or(expected_root))
+    } else if source_count == 1 {
+        Some(shared_root.to_path_buf())
+    } else {
+        None
+    }
 }
 
 fn import_library_manifest(
@@ -487,6 +1308,7 @@ fn import_library_manifest(
     let mut added = 0usize;
     let mut existing = 0usize;
     let mut missing = 0usize;
+    let mut resolved_roots: HashMap<String, PathBuf> = HashMap::new();
 
     let mut updated_sources = load_sources(app);
     let mut known_paths: HashSet<PathBuf> = updated_sources
@@ -495,14 +1317,8 @@ fn import_library_manifest(
         .collect();
 
     for source in &manifest.sources {
-        let expected_root = shared_root.join(&source.root_name);
-        let resolved_path = if is_directory(&expected_root) {
-            Some(fs::canonicalize(&expected_root).unwrap_or(expected_root))
-        } else if manifest.sources.len() == 1 {
-            Some(shared_root.clone())
-        } else {
-            None
-        };
+        let resolved_path =
+            resolve_manifest_source_root(&shared_root, source, manifest.sources.len());
 
         let Some(resolved_path) = resolved_path else {
             missing += 1;
@@ -536,6 +1352,8 @@ fn import_library_manifest(
             }
         }
 
+        resolved_roots.insert(source.name.clone(), resolved_path.clone());
+
         if known_paths.contains(&resolved_path) {
             existing += 1;
             results.push(LibraryImportSourceResult {
@@ -550,6 +1368,7 @@ fn import_library_manifest(
         updated_sources.push(LibrarySource {
             name: source.name.clone(),
             path: resolved_path.to_string_lossy().to_string(),
+            scopes: source.scopes.clone(),
         });
         known_paths.insert(resolved_path.clone());
         added += 1;
@@ -563,11 +1382,14 @@ fn import_library_manifest(
 
     save_sources(app, &updated_sources)?;
 
+    let (verification, _entries) = verify_manifest_entries(manifest, &resolved_roots);
+
     Ok(LibraryManifestImportSummary {
         added,
         existing,
         missing,
         results,
+        verification,
     })
 }
 
@@ -577,24 +1399,122 @@ fn path_file_url(path: &Path) -> Option<String> {
         .map(|url| url.to_string())
 }
 
-fn format_title_from_path(file_path: &Path) -> String {
-    file_path
-        .file_stem()
-        .and_then(|stem| stem.to_str())
-        .map(|stem| stem.replace('-', " "))
-        .unwrap_or_else(|| "Untitled".to_string())
+fn format_title_from_path(file_path: &Path) -> String {
+    file_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(|stem| stem.replace('-', " "))
+        .unwrap_or_else(|| "Untitled".to_string())
+}
+
+/// Windows-1252 code points for byte values 0x80-0x9F; 0xA0-0xFF map onto Latin-1 directly.
+const WINDOWS_1252_HIGH_CONTROL_RANGE: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+fn decode_windows_1252(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&byte| {
+            if (0x80..=0x9F).contains(&byte) {
+                WINDOWS_1252_HIGH_CONTROL_RANGE[(byte - 0x80) as usize]
+            } else {
+                byte as char
+            }
+        })
+        .collect()
+}
+
+fn decode_utf16_bytes(bytes: &[u8], little_endian: bool) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|chunk| {
+            if little_endian {
+                u16::from_le_bytes([chunk[0], chunk[1]])
+            } else {
+                u16::from_be_bytes([chunk[0], chunk[1]])
+            }
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Counts high-bit bytes that don't form a well-formed multi-byte UTF-8 sequence.
+fn looks_like_windows_1252(bytes: &[u8]) -> bool {
+    let mut high_bit_bytes = 0usize;
+    let mut invalid_sequences = 0usize;
+    let mut index = 0usize;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte < 0x80 {
+            index += 1;
+            continue;
+        }
+
+        high_bit_bytes += 1;
+        let expected_len = if byte & 0xE0 == 0xC0 {
+            2
+        } else if byte & 0xF0 == 0xE0 {
+            3
+        } else if byte & 0xF8 == 0xF0 {
+            4
+        } else {
+            0
+        };
+
+        let is_valid_sequence = expected_len > 0
+            && index + expected_len <= bytes.len()
+            && std::str::from_utf8(&bytes[index..index + expected_len]).is_ok();
+
+        if is_valid_sequence {
+            index += expected_len;
+        } else {
+            invalid_sequences += 1;
+            index += 1;
+        }
+    }
+
+    high_bit_bytes > 0 && invalid_sequences * 2 >= high_bit_bytes
+}
+
+/// Sniffs a BOM, then tries strict UTF-8, then a Windows-1252 heuristic, then a lossy UTF-8 decode.
+fn decode_text_bytes(bytes: &[u8]) -> (String, &'static str) {
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return (String::from_utf8_lossy(rest).into_owned(), "utf-8-bom");
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return (decode_utf16_bytes(rest, true), "utf-16le");
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return (decode_utf16_bytes(rest, false), "utf-16be");
+    }
+
+    if let Ok(content) = std::str::from_utf8(bytes) {
+        return (content.to_string(), "utf-8");
+    }
+
+    if looks_like_windows_1252(bytes) {
+        return (decode_windows_1252(bytes), "windows-1252");
+    }
+
+    (String::from_utf8_lossy(bytes).into_owned(), "utf-8-lossy")
 }
 
-fn read_text_file(file_path: &Path) -> Result<String, String> {
-    fs::read_to_string(file_path)
-        .map_err(|err| format!("Failed to read file {}: {err}", file_path.display()))
+fn read_text_file(file_path: &Path) -> Result<(String, &'static str), String> {
+    let bytes = fs::read(file_path)
+        .map_err(|err| format!("Failed to read file {}: {err}", file_path.display()))?;
+    Ok(decode_text_bytes(&bytes))
 }
 
 fn open_text_content(
     requested_path: &Path,
     content_path: &Path,
 ) -> Result<ExtractedContent, String> {
-    let content = read_text_file(content_path)?;
+    let (content, detected_encoding) = read_text_file(content_path)?;
     let asset_base_url = requested_path.parent().and_then(path_file_url);
     Ok(ExtractedContent {
         title: format_title_from_path(requested_path),
@@ -603,6 +1523,7 @@ fn open_text_content(
         asset_base_url,
         page_count: None,
         chapters: None,
+        detected_encoding: Some(detected_encoding.to_string()),
     })
 }
 
@@ -707,6 +1628,235 @@ fn find_corpus_path(app: &AppHandle, family: &str, tier: &str) -> Option<PathBuf
     None
 }
 
+fn corpus_index_path(jsonl_path: &Path) -> PathBuf {
+    jsonl_path.with_extension("idx")
+}
+
+fn file_mtime_millis(metadata: &fs::Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|timestamp| timestamp.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn sorted_record_order_by_grade(records: &[CorpusIndexRecord]) -> Vec<u32> {
+    let mut order: Vec<u32> = (0..records.len() as u32).collect();
+    order.sort_by(|&a, &b| {
+        records[a as usize]
+            .fk_grade
+            .partial_cmp(&records[b as usize].fk_grade)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+fn corpus_records_in_grade_range(
+    records: &[CorpusIndexRecord],
+    sorted_by_grade: &[u32],
+    min_grade: f64,
+    max_grade: f64,
+) -> Vec<u32> {
+    let start =
+        sorted_by_grade.partition_point(|&index| records[index as usize].fk_grade < min_grade);
+    let end =
+        sorted_by_grade.partition_point(|&index| records[index as usize].fk_grade <= max_grade);
+    sorted_by_grade[start..end].to_vec()
+}
+
+/// Scans the JSONL once, recording each line's byte offset/length plus its reading-level fields.
+fn build_corpus_index(jsonl_path: &Path) -> Result<Vec<CorpusIndexRecord>, String> {
+    let bytes =
+        fs::read(jsonl_path).map_err(|err| format!("Failed to read corpus file: {err}"))?;
+
+    let mut records = vec![];
+    let mut line_start = 0usize;
+    for position in 0..=bytes.len() {
+        let at_boundary = position == bytes.len() || bytes[position] == b'\n';
+        if !at_boundary {
+            continue;
+        }
+
+        let line = &bytes[line_start..position];
+        if !line.iter().all(|byte| byte.is_ascii_whitespace()) {
+            if let Ok(article) = serde_json::from_slice::<CorpusArticle>(line) {
+                records.push(CorpusIndexRecord {
+                    offset: line_start as u64,
+                    length: (position - line_start) as u32,
+                    fk_grade: article.fk_grade,
+                    words: article.words,
+                });
+            }
+        }
+
+        line_start = position + 1;
+    }
+
+    Ok(records)
+}
+
+fn write_corpus_index(
+    idx_path: &Path,
+    source_len: u64,
+    source_mtime: u64,
+    records: &[CorpusIndexRecord],
+    sorted_by_grade: &[u32],
+) -> Result<(), String> {
+    let mut buffer: Vec<u8> = Vec::new();
+    buffer.extend_from_slice(CORPUS_INDEX_MAGIC);
+    let schema_bytes = CORPUS_INDEX_SCHEMA.as_bytes();
+    buffer.extend_from_slice(&(schema_bytes.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(schema_bytes);
+    buffer.extend_from_slice(&CORPUS_INDEX_VERSION.to_le_bytes());
+    buffer.extend_from_slice(&(records.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&source_len.to_le_bytes());
+    buffer.extend_from_slice(&source_mtime.to_le_bytes());
+
+    for record in records {
+        buffer.extend_from_slice(&record.offset.to_le_bytes());
+        buffer.extend_from_slice(&record.length.to_le_bytes());
+        buffer.extend_from_slice(&record.fk_grade.to_le_bytes());
+        buffer.extend_from_slice(&record.words.to_le_bytes());
+    }
+
+    for index in sorted_by_grade {
+        buffer.extend_from_slice(&index.to_le_bytes());
+    }
+
+    fs::write(idx_path, buffer).map_err(|err| format!("Failed to write corpus index: {err}"))
+}
+
+/// Returns `None` (triggering a rebuild) whenever the index is missing, malformed, or stale.
+fn read_corpus_index(
+    idx_path: &Path,
+    source_len: u64,
+    source_mtime: u64,
+) -> Option<(Vec<CorpusIndexRecord>, Vec<u32>)> {
+    let bytes = fs::read(idx_path).ok()?;
+    if bytes.len() < CORPUS_INDEX_MAGIC.len() || &bytes[..CORPUS_INDEX_MAGIC.len()] != CORPUS_INDEX_MAGIC {
+        return None;
+    }
+    let mut offset = CORPUS_INDEX_MAGIC.len();
+
+    let schema_len = u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?) as usize;
+    offset += 4;
+    let schema = std::str::from_utf8(bytes.get(offset..offset + schema_len)?).ok()?;
+    if schema != CORPUS_INDEX_SCHEMA {
+        return None;
+    }
+    offset += schema_len;
+
+    let version = u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?);
+    offset += 4;
+    if version != CORPUS_INDEX_VERSION {
+        return None;
+    }
+
+    let article_count =
+        u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?) as usize;
+    offset += 4;
+
+    let stored_source_len = u64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?);
+    offset += 8;
+    let stored_source_mtime = u64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?);
+    offset += 8;
+
+    if stored_source_len != source_len || stored_source_mtime != source_mtime {
+        return None;
+    }
+
+    let mut records = Vec::with_capacity(article_count);
+    for _ in 0..article_count {
+        let record_offset = u64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?);
+        offset += 8;
+        let length = u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?);
+        offset += 4;
+        let fk_grade = f64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?);
+        offset += 8;
+        let words = u64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?);
+        offset += 8;
+        records.push(CorpusIndexRecord {
+            offset: record_offset,
+            length,
+            fk_grade,
+            words,
+        });
+    }
+
+    let mut sorted_by_grade = Vec::with_capacity(article_count);
+    for _ in 0..article_count {
+        let index = u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?);
+        offset += 4;
+        if index as usize >= article_count {
+            return None;
+        }
+        sorted_by_grade.push(index);
+    }
+
+    Some((records, sorted_by_grade))
+}
+
+/// Network filesystems make `mmap` unreliable (SIGBUS on a dropped connection), so they fall back to buffered reads.
+fn is_network_filesystem(path: &Path) -> bool {
+    let Ok(mountinfo) = fs::read_to_string("/proc/self/mountinfo") else {
+        return false;
+    };
+    let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+    let mut best_match: Option<(usize, String)> = None;
+    for line in mountinfo.lines() {
+        let Some(separator) = line.find(" - ") else {
+            continue;
+        };
+        let Some(mount_point) = line[..separator].split_whitespace().nth(4) else {
+            continue;
+        };
+        let Some(fstype) = line[separator + 3..].split_whitespace().next() else {
+            continue;
+        };
+
+        if !canonical.starts_with(mount_point) {
+            continue;
+        }
+        let is_more_specific = best_match
+            .as_ref()
+            .map(|(len, _)| mount_point.len() > *len)
+            .unwrap_or(true);
+        if is_more_specific {
+            best_match = Some((mount_point.len(), fstype.to_string()));
+        }
+    }
+
+    best_match
+        .map(|(_, fstype)| NETWORK_FILESYSTEM_TYPES.contains(&fstype.as_str()))
+        .unwrap_or(false)
+}
+
+fn open_corpus_source(jsonl_path: &Path) -> Result<CorpusSourceBytes, String> {
+    if !is_network_filesystem(jsonl_path) {
+        if let Ok(file) = fs::File::open(jsonl_path) {
+            if let Ok(mmap) = unsafe { Mmap::map(&file) } {
+                return Ok(CorpusSourceBytes::Mapped(mmap));
+            }
+        }
+    }
+
+    fs::read(jsonl_path)
+        .map(CorpusSourceBytes::Buffered)
+        .map_err(|err| format!("Failed to read corpus file: {err}"))
+}
+
+fn parse_corpus_record(
+    source: &CorpusSourceBytes,
+    record: &CorpusIndexRecord,
+) -> Option<CorpusArticle> {
+    let start = record.offset as usize;
+    let end = start + record.length as usize;
+    let slice = source.as_slice().get(start..end)?;
+    serde_json::from_slice(slice).ok()
+}
+
 fn ensure_corpus_loaded(
     state: &AppState,
     app: &AppHandle,
@@ -729,25 +1879,37 @@ fn ensure_corpus_loaded(
         return Ok(false);
     };
 
-    let Ok(content) = fs::read_to_string(corpus_path) else {
+    let Ok(metadata) = fs::metadata(&corpus_path) else {
         return Ok(false);
     };
-
-    let mut articles: Vec<CorpusArticle> = vec![];
-    for line in content.lines() {
-        if line.trim().is_empty() {
-            continue;
+    let source_len = metadata.len();
+    let source_mtime = file_mtime_millis(&metadata);
+
+    let idx_path = corpus_index_path(&corpus_path);
+    let (records, sorted_by_grade) = match read_corpus_index(&idx_path, source_len, source_mtime) {
+        Some(loaded) => loaded,
+        None => {
+            let records = build_corpus_index(&corpus_path)?;
+            let sorted_by_grade = sorted_record_order_by_grade(&records);
+            let _ = write_corpus_index(&idx_path, source_len, source_mtime, &records, &sorted_by_grade);
+            (records, sorted_by_grade)
         }
-        if let Ok(article) = serde_json::from_str::<CorpusArticle>(line) {
-            articles.push(article);
-        }
-    }
+    };
+
+    let source = open_corpus_source(&corpus_path)?;
 
     let mut cache = state
         .corpus_cache
         .lock()
         .map_err(|_| "Corpus cache lock poisoned".to_string())?;
-    cache.insert(key, articles);
+    cache.insert(
+        key,
+        CorpusIndex {
+            records,
+            sorted_by_grade,
+            source,
+        },
+    );
 
     Ok(true)
 }
@@ -759,13 +1921,26 @@ fn library_get_sources(app: AppHandle) -> Vec<LibrarySource> {
 
 #[tauri::command]
 fn library_list_books(app: AppHandle, dir_path: String) -> Result<Vec<LibraryItem>, String> {
-    let allowed_path = resolve_allowed_library_path(&app, &dir_path)?;
-    Ok(scan_directory(&allowed_path))
+    let (allowed_path, source_root, scopes) = resolve_allowed_library_path(&app, &dir_path)?;
+    Ok(scan_directory(&app, &allowed_path, false, &scopes, &source_root))
+}
+
+#[tauri::command]
+fn library_rescan(app: AppHandle, source_name: String) -> Result<usize, String> {
+    let sources = load_sources(&app);
+    let source = sources
+        .iter()
+        .find(|source| source.name == source_name)
+        .ok_or_else(|| format!("Unknown library source: {source_name}"))?;
+    let root_path =
+        normalize_path(&source.path).ok_or_else(|| "Directory does not exist".to_string())?;
+
+    Ok(scan_directory(&app, &root_path, true, &source.scopes, &root_path).len())
 }
 
 #[tauri::command]
 fn library_open_book(app: AppHandle, file_path: String) -> Result<ExtractedContent, String> {
-    let allowed_path = resolve_allowed_library_path(&app, &file_path)?;
+    let (allowed_path, _source_root, _scopes) = resolve_allowed_library_path(&app, &file_path)?;
     let extension = allowed_path
         .extension()
         .and_then(|ext| ext.to_str())
@@ -821,6 +1996,7 @@ fn library_add_source(app: AppHandle, source: LibrarySource) -> Result<(), Strin
     sources.push(LibrarySource {
         name: normalized_name,
         path: normalized_string,
+        scopes: source.scopes,
     });
     save_sources(&app, &sources)
 }
@@ -830,7 +2006,9 @@ fn library_remove_source(app: AppHandle, source_path: String) -> Result<(), Stri
     let target = normalize_path_for_compare(&source_path);
     let mut sources = load_sources(&app);
     sources.retain(|source| normalize_path_for_compare(&source.path) != target);
-    save_sources(&app, &sources)
+    save_sources(&app, &sources)?;
+    remove_scan_cache_source(&app, &target);
+    Ok(())
 }
 
 #[tauri::command]
@@ -872,7 +2050,7 @@ fn library_export_manifest(app: AppHandle) -> Result<LibraryExportResult, String
         });
     };
 
-    let manifest = build_library_manifest(&sources);
+    let manifest = build_library_manifest(&app, &sources);
     save_library_manifest(&manifest, &save_path)?;
 
     Ok(LibraryExportResult {
@@ -898,6 +2076,8 @@ fn library_import_manifest(app: AppHandle) -> Result<LibraryImportResult, String
             existing: None,
             missing: None,
             results: None,
+            verification: None,
+            errors: None,
         });
     };
 
@@ -913,10 +2093,27 @@ fn library_import_manifest(app: AppHandle) -> Result<LibraryImportResult, String
             existing: None,
             missing: None,
             results: None,
+            verification: None,
+            errors: None,
         });
     };
 
-    let manifest = load_library_manifest(&manifest_path)?;
+    let manifest = match load_and_validate_library_manifest(&manifest_path)? {
+        ManifestLoadOutcome::Valid(manifest) => manifest,
+        ManifestLoadOutcome::Invalid(errors) => {
+            return Ok(LibraryImportResult {
+                status: "invalid".to_string(),
+                manifest_path: Some(manifest_path.to_string_lossy().to_string()),
+                shared_root_path: Some(shared_root_path.to_string_lossy().to_string()),
+                added: None,
+                existing: None,
+                missing: None,
+                results: None,
+                verification: None,
+                errors: Some(errors),
+            });
+        }
+    };
     let summary = import_library_manifest(&app, &manifest, &shared_root_path)?;
 
     Ok(LibraryImportResult {
@@ -927,6 +2124,250 @@ fn library_import_manifest(app: AppHandle) -> Result<LibraryImportResult, String
         existing: Some(summary.existing),
         missing: Some(summary.missing),
         results: Some(summary.results),
+        verification: Some(summary.verification),
+        errors: None,
+    })
+}
+
+#[tauri::command]
+fn library_export_manifest_schema(target_path: String) -> Result<String, String> {
+    let schema = library_manifest_json_schema();
+    let payload = serde_json::to_string_pretty(&schema)
+        .map_err(|err| format!("Failed to serialize manifest schema: {err}"))?;
+
+    let target = PathBuf::from(&target_path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create schema directory: {err}"))?;
+    }
+    fs::write(&target, payload).map_err(|err| format!("Failed to write manifest schema: {err}"))?;
+
+    Ok(target.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+fn library_verify_manifest(
+    manifest_path: String,
+    shared_root: String,
+) -> Result<LibraryManifestVerifyResult, String> {
+    let manifest = load_library_manifest(Path::new(&manifest_path))?;
+    let shared_root_path = fs::canonicalize(&shared_root)
+        .map_err(|_| "Shared root is not a directory".to_string())?;
+    if !is_directory(&shared_root_path) {
+        return Err("Shared root is not a directory".to_string());
+    }
+
+    let resolved_roots: HashMap<String, PathBuf> = manifest
+        .sources
+        .iter()
+        .filter_map(|source| {
+            resolve_manifest_source_root(&shared_root_path, source, manifest.sources.len())
+                .map(|root| (source.name.clone(), root))
+        })
+        .collect();
+
+    let (summary, entries) = verify_manifest_entries(&manifest, &resolved_roots);
+
+    Ok(LibraryManifestVerifyResult {
+        status: "verified".to_string(),
+        summary,
+        entries,
+    })
+}
+
+#[tauri::command]
+fn library_export_archive(app: AppHandle, target_path: String) -> Result<LibraryExportResult, String> {
+    let sources = load_sources(&app);
+    if sources.is_empty() {
+        return Err("No library sources configured".to_string());
+    }
+
+    let manifest = build_library_manifest(&app, &sources);
+    let header_bytes = serde_json::to_vec(&manifest)
+        .map_err(|err| format!("Failed to serialize manifest header: {err}"))?;
+
+    let target = PathBuf::from(&target_path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create archive directory: {err}"))?;
+    }
+    let file =
+        fs::File::create(&target).map_err(|err| format!("Failed to create archive: {err}"))?;
+    let mut writer = BufWriter::new(file);
+
+    writer
+        .write_all(LIBRARY_ARCHIVE_MAGIC)
+        .map_err(|err| format!("Failed to write archive header: {err}"))?;
+    writer
+        .write_all(&(header_bytes.len() as u32).to_le_bytes())
+        .map_err(|err| format!("Failed to write archive header: {err}"))?;
+    writer
+        .write_all(&header_bytes)
+        .map_err(|err| format!("Failed to write archive header: {err}"))?;
+
+    let source_roots: HashMap<String, PathBuf> = sources
+        .iter()
+        .filter_map(|source| normalize_path(&source.path).map(|root| (source.name.clone(), root)))
+        .collect();
+    let root_names: HashMap<String, String> = manifest
+        .sources
+        .iter()
+        .map(|source| (source.name.clone(), source.root_name.clone()))
+        .collect();
+
+    let mut written_frames: HashSet<String> = HashSet::new();
+    for entry in &manifest.entries {
+        let Some(source_root) = source_roots.get(&entry.source_name) else {
+            continue;
+        };
+        let Some(root_name) = root_names.get(&entry.source_name) else {
+            continue;
+        };
+
+        let frame_path = normalize_manifest_path(&PathBuf::from(root_name).join(&entry.relative_path));
+        if written_frames.insert(frame_path.clone()) {
+            write_archive_frame(&mut writer, source_root, &entry.relative_path, &frame_path)?;
+        }
+
+        if let Some(sidecar_relative) = &entry.normalized_text_relative_path {
+            let sidecar_frame_path =
+                normalize_manifest_path(&PathBuf::from(root_name).join(sidecar_relative));
+            if written_frames.insert(sidecar_frame_path.clone()) {
+                write_archive_frame(&mut writer, source_root, sidecar_relative, &sidecar_frame_path)?;
+            }
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("Failed to finalize archive: {err}"))?;
+
+    Ok(LibraryExportResult {
+        status: "exported".to_string(),
+        path: Some(target.to_string_lossy().to_string()),
+        source_count: Some(manifest.sources.len()),
+        entry_count: Some(manifest.entries.len()),
+    })
+}
+
+#[tauri::command]
+fn library_import_archive(
+    app: AppHandle,
+    archive_path: String,
+    destination_dir: String,
+) -> Result<LibraryImportResult, String> {
+    fs::create_dir_all(&destination_dir)
+        .map_err(|err| format!("Failed to create destination directory: {err}"))?;
+    let destination = fs::canonicalize(&destination_dir)
+        .map_err(|_| "Destination is not a directory".to_string())?;
+
+    let file = fs::File::open(&archive_path)
+        .map_err(|err| format!("Failed to open archive: {err}"))?;
+    let mut reader = BufReader::new(file);
+
+    let mut magic = [0u8; 8];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|err| format!("Failed to read archive header: {err}"))?;
+    if &magic != LIBRARY_ARCHIVE_MAGIC {
+        return Err("Unsupported library archive format".to_string());
+    }
+
+    let mut header_len_buf = [0u8; 4];
+    reader
+        .read_exact(&mut header_len_buf)
+        .map_err(|err| format!("Failed to read archive header length: {err}"))?;
+    let header_len = u32::from_le_bytes(header_len_buf) as usize;
+    let mut header_bytes = vec![0u8; header_len];
+    reader
+        .read_exact(&mut header_bytes)
+        .map_err(|err| format!("Failed to read archive header: {err}"))?;
+    let header_document: serde_json::Value = serde_json::from_slice(&header_bytes)
+        .map_err(|err| format!("Invalid archive manifest header: {err}"))?;
+    let validation_errors = validate_manifest_document(&header_document);
+    if !validation_errors.is_empty() {
+        let summary = validation_errors
+            .iter()
+            .map(|error| format!("{} ({})", error.message, error.json_path))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(format!("Invalid archive manifest header: {summary}"));
+    }
+    let manifest: LibraryManifest = serde_json::from_value(header_document)
+        .map_err(|err| format!("Invalid archive manifest header: {err}"))?;
+
+    while let Some((frame_path, size)) = read_archive_frame(&mut reader)? {
+        reject_unsafe_relative_path(&frame_path)?;
+        let destination_file = destination.join(&frame_path);
+        if let Some(parent) = destination_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Failed to create folder for {frame_path}: {err}"))?;
+        }
+
+        let mut out_file = fs::File::create(&destination_file)
+            .map_err(|err| format!("Failed to create {frame_path}: {err}"))?;
+        let mut limited_reader = (&mut reader).take(size);
+        let copied = std::io::copy(&mut limited_reader, &mut out_file)
+            .map_err(|err| format!("Failed to extract {frame_path}: {err}"))?;
+        if copied != size {
+            return Err(format!("Archive is truncated at {frame_path}"));
+        }
+    }
+
+    let mut updated_sources = load_sources(&app);
+    let mut known_paths: HashSet<PathBuf> = updated_sources
+        .iter()
+        .map(|source| normalize_path_for_compare(&source.path))
+        .collect();
+    let mut results: Vec<LibraryImportSourceResult> = vec![];
+    let mut added = 0usize;
+    let mut existing = 0usize;
+
+    for source in &manifest.sources {
+        reject_unsafe_relative_path(&source.root_name)?;
+        let resolved_path = destination.join(&source.root_name);
+        fs::create_dir_all(&resolved_path)
+            .map_err(|err| format!("Failed to create folder \"{}\": {err}", source.root_name))?;
+        let resolved_path = fs::canonicalize(&resolved_path).unwrap_or(resolved_path);
+
+        if known_paths.contains(&resolved_path) {
+            existing += 1;
+            results.push(LibraryImportSourceResult {
+                source_name: source.name.clone(),
+                status: "existing".to_string(),
+                resolved_path: Some(resolved_path.to_string_lossy().to_string()),
+                message: "Source already configured".to_string(),
+            });
+            continue;
+        }
+
+        updated_sources.push(LibrarySource {
+            name: source.name.clone(),
+            path: resolved_path.to_string_lossy().to_string(),
+            scopes: source.scopes.clone(),
+        });
+        known_paths.insert(resolved_path.clone());
+        added += 1;
+        results.push(LibraryImportSourceResult {
+            source_name: source.name.clone(),
+            status: "added".to_string(),
+            resolved_path: Some(resolved_path.to_string_lossy().to_string()),
+            message: "Source added".to_string(),
+        });
+    }
+
+    save_sources(&app, &updated_sources)?;
+
+    Ok(LibraryImportResult {
+        status: "imported".to_string(),
+        manifest_path: Some(archive_path),
+        shared_root_path: Some(destination.to_string_lossy().to_string()),
+        added: Some(added),
+        existing: Some(existing),
+        missing: Some(0),
+        results: Some(results),
+        verification: None,
+        errors: None,
     })
 }
 
@@ -964,7 +2405,7 @@ fn corpus_get_info(
                 .lock()
                 .map_err(|_| "Corpus cache lock poisoned".to_string())?
                 .get(&key)
-                .map(|articles| articles.len())
+                .map(|index| index.records.len())
                 .unwrap_or(0);
 
             tiers.insert(
@@ -1001,10 +2442,10 @@ fn corpus_sample_article(
         .corpus_cache
         .lock()
         .map_err(|_| "Corpus cache lock poisoned".to_string())?;
-    let Some(articles) = cache.get(&key) else {
+    let Some(index) = cache.get(&key) else {
         return Ok(None);
     };
-    if articles.is_empty() {
+    if index.records.is_empty() {
         return Ok(None);
     }
 
@@ -1012,8 +2453,78 @@ fn corpus_sample_article(
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
         .as_nanos() as usize;
-    let index = now % articles.len();
-    Ok(Some(articles[index].clone()))
+    let record_index = now % index.records.len();
+    Ok(parse_corpus_record(&index.source, &index.records[record_index]))
+}
+
+#[tauri::command]
+fn corpus_get_article(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    family: String,
+    tier: String,
+    idx: usize,
+) -> Result<Option<CorpusArticle>, String> {
+    if !CORPUS_FAMILIES.contains(&family.as_str()) || !CORPUS_TIERS.contains(&tier.as_str()) {
+        return Ok(None);
+    }
+
+    if !ensure_corpus_loaded(&state, &app, &family, &tier)? {
+        return Ok(None);
+    }
+
+    let key = corpus_key(&family, &tier);
+    let cache = state
+        .corpus_cache
+        .lock()
+        .map_err(|_| "Corpus cache lock poisoned".to_string())?;
+    let Some(index) = cache.get(&key) else {
+        return Ok(None);
+    };
+    let Some(record) = index.records.get(idx) else {
+        return Ok(None);
+    };
+
+    Ok(parse_corpus_record(&index.source, record))
+}
+
+#[tauri::command]
+fn corpus_query_grade_range(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    family: String,
+    tier: String,
+    min_grade: f64,
+    max_grade: f64,
+) -> Result<Vec<CorpusArticle>, String> {
+    if !CORPUS_FAMILIES.contains(&family.as_str()) || !CORPUS_TIERS.contains(&tier.as_str()) {
+        return Ok(vec![]);
+    }
+
+    if !ensure_corpus_loaded(&state, &app, &family, &tier)? {
+        return Ok(vec![]);
+    }
+
+    let key = corpus_key(&family, &tier);
+    let cache = state
+        .corpus_cache
+        .lock()
+        .map_err(|_| "Corpus cache lock poisoned".to_string())?;
+    let Some(index) = cache.get(&key) else {
+        return Ok(vec![]);
+    };
+
+    let matching =
+        corpus_records_in_grade_range(&index.records, &index.sorted_by_grade, min_grade, max_grade);
+    Ok(matching
+        .into_iter()
+        .filter_map(|record_index| {
+            index
+                .records
+                .get(record_index as usize)
+                .and_then(|record| parse_corpus_record(&index.source, record))
+        })
+        .collect())
 }
 
 fn main() {
@@ -1022,17 +2533,24 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             library_get_sources,
             library_list_books,
+            library_rescan,
             library_open_book,
             library_add_source,
             library_remove_source,
             library_select_directory,
             library_export_manifest,
             library_import_manifest,
+            library_verify_manifest,
+            library_export_manifest_schema,
+            library_export_archive,
+            library_import_archive,
             secure_keys_is_available,
             secure_keys_get,
             secure_keys_set,
             corpus_get_info,
-            corpus_sample_article
+            corpus_sample_article,
+            corpus_get_article,
+            corpus_query_grade_range
         ])
         .run(tauri::generate_context!())
         .expect("error while running Reader Tauri app");